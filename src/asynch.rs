@@ -0,0 +1,323 @@
+//! Async mirror of [`Arducam`](crate::Arducam), built on `embedded-hal-async`.
+//!
+//! The blocking `Arducam` holds the SPI bus for the whole FIFO burst read
+//! and spins on `is_capture_done` in a tight loop, which blocks an entire
+//! executor task on platforms like Embassy. `AsyncArducam` mirrors the
+//! capture path of that API (`init`, `set_resolution`, `is_connected`,
+//! `start_capture`, `is_capture_done`, `get_fifo_length`,
+//! `get_sensor_chipid` and `read_captured_image_chunked`) but `.await`s every
+//! SPI/I2C transfer, letting other tasks run while a DMA-backed burst read or
+//! the capture-done poll is in flight. It talks to an OV2640 only and has no
+//! image-tuning, BMP or blocking-capture methods.
+//!
+//! This module is only compiled when the `async` feature is enabled.
+
+use embedded_hal_async::{delay::DelayNs, i2c::I2c, spi::SpiDevice};
+
+use crate::ov2640_registers::*;
+use crate::{Error, ImageFormat, Resolution};
+
+const ARDUCHIP_TEST1: u8 = 0x00;
+const ARDUCHIP_FIFO: u8 = 0x04;
+const ARDUCHIP_TRIG: u8 = 0x41;
+const OV2640_ADDR: u8 = 0x60 >> 1;
+const OV2640_CHIPID_HIGH: u8 = 0x0A;
+const OV2640_CHIPID_LOW: u8 = 0x0B;
+const FIFO_CLEAR_MASK: u8 = 0x01;
+const FIFO_START_MASK: u8 = 0x02;
+const FIFO_BURST: u8 = 0x3C;
+const FIFO_SIZE1: u8 = 0x42;
+const FIFO_SIZE2: u8 = 0x43;
+const FIFO_SIZE3: u8 = 0x44;
+const CAP_DONE_MASK: u8 = 0x08;
+const WRITE_FLAG: u8 = 0x80;
+
+/// Async mirror of [`Arducam`](crate::Arducam). See the module docs for why
+/// this exists; the register-level protocol is identical to the blocking
+/// driver, just `.await`ed.
+pub struct AsyncArducam<SPI, I2C> {
+    spi: SPI,
+    i2c: I2C,
+    format: ImageFormat,
+    resolution: Resolution,
+}
+
+impl<SPI, I2C> AsyncArducam<SPI, I2C>
+where
+    SPI: SpiDevice,
+    I2C: I2c,
+{
+    /// Creates a new AsyncArducam instance but doesn't initialize it
+    pub fn new(spi: SPI, i2c: I2C, resolution: Resolution, format: ImageFormat) -> Self {
+        AsyncArducam {
+            spi,
+            i2c,
+            format,
+            resolution,
+        }
+    }
+
+    /// Initializes Arducam to resetted state
+    pub async fn init<D>(&mut self, delay: &mut D) -> Result<(), Error>
+    where
+        D: DelayNs,
+    {
+        self.arduchip_write_reg(0x07, 0x80).await?;
+        delay.delay_ms(100).await;
+        self.arduchip_write_reg(0x07, 0x00).await?;
+        delay.delay_ms(100).await;
+        self.sensor_writereg8_8(0xFF, 0x01).await?;
+        delay.delay_ms(100).await;
+        self.sensor_writereg8_8(0x12, 0x80).await?;
+        delay.delay_ms(100).await;
+
+        self.sensor_writeregs8_8(&OV2640_JPEG_INIT).await?;
+        self.sensor_writeregs8_8(&OV2640_YUV422).await?;
+        match self.format {
+            ImageFormat::JPEG => {
+                self.sensor_writeregs8_8(&OV2640_JPEG).await?;
+                self.sensor_writereg8_8(0xFF, 0x01).await?;
+                self.sensor_writereg8_8(0x15, 0x00).await?;
+            }
+            ImageFormat::Bmp | ImageFormat::Raw => {
+                self.sensor_writeregs8_8(&OV2640_RGB565).await?;
+            }
+        }
+        self.send_resolution().await?;
+
+        Ok(())
+    }
+
+    /// Sets camera resolution
+    pub async fn set_resolution(&mut self, resolution: Resolution) -> Result<(), Error> {
+        self.resolution = resolution;
+        self.send_resolution().await?;
+        Ok(())
+    }
+
+    /// Checks if Arducam is still connected to SPI bus
+    pub async fn is_connected(&mut self) -> Result<bool, Error> {
+        let test_value = 0x52;
+        self.arduchip_write_reg(ARDUCHIP_TEST1, test_value).await?;
+        let result = self.arduchip_read_reg(ARDUCHIP_TEST1).await?;
+
+        let valid_ov2640_chipid1 = [0x26, 0x41];
+        let valid_ov2640_chipid2 = [0x26, 0x42];
+        let chipid = self.get_sensor_chipid().await?;
+
+        if test_value == result && (chipid == valid_ov2640_chipid1 || chipid == valid_ov2640_chipid2) {
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Sends image capture request
+    pub async fn start_capture(&mut self) -> Result<(), Error> {
+        self.flush_fifo().await?;
+        self.start_fifo().await?;
+        Ok(())
+    }
+
+    /// Checks if image capture is done
+    pub async fn is_capture_done(&mut self) -> Result<bool, Error> {
+        self.arduchip_read_reg(ARDUCHIP_TRIG)
+            .await
+            .map(|result| result & CAP_DONE_MASK != 0)
+    }
+
+    /// Returns image length reported by arduchip in FIFO
+    pub async fn get_fifo_length(&mut self) -> Result<u32, Error> {
+        let mut len_builder = (0u32, 0u32, 0u32);
+        len_builder.0 = self.arduchip_read_reg(FIFO_SIZE1).await?.into();
+        len_builder.1 = self.arduchip_read_reg(FIFO_SIZE2).await?.into();
+        len_builder.2 = (self.arduchip_read_reg(FIFO_SIZE3).await? & 0x7F).into();
+        Ok((len_builder.2 << 16 | len_builder.1 << 8 | len_builder.0) & 0x7FFFFFu32)
+    }
+
+    /// Returns sensor vendor and product ID
+    pub async fn get_sensor_chipid(&mut self) -> Result<[u8; 2], Error> {
+        let mut chipid: [u8; 2] = [0; 2];
+        self.sensor_writereg8_8(0xFF, 0x01).await?;
+        self.sensor_readreg8_8(OV2640_CHIPID_HIGH, &mut chipid[0..1])
+            .await?;
+        self.sensor_readreg8_8(OV2640_CHIPID_LOW, &mut chipid[1..2])
+            .await?;
+        Ok(chipid)
+    }
+
+    /// Streams a captured image out of the FIFO in fixed-size blocks,
+    /// `.await`ing each SPI burst read and handing each block to `f` as it
+    /// arrives. See [`Arducam::read_captured_image_chunked`] for the JPEG
+    /// SOI/EOI boundary detection this performs.
+    ///
+    /// [`Arducam::read_captured_image_chunked`]: crate::Arducam::read_captured_image_chunked
+    pub async fn read_captured_image_chunked<const N: usize>(
+        &mut self,
+        mut f: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<u32, Error> {
+        let mut remaining = self.get_fifo_length().await?;
+        let mut buf = [0u8; N];
+        let mut first_chunk = true;
+
+        let mut found_soi = false;
+        let mut found_eoi = false;
+        let mut carry: Option<u8> = None;
+        let mut emitted = 0u32;
+
+        while remaining > 0 && !found_eoi {
+            let read_len = core::cmp::min(remaining as usize, N);
+            let chunk = &mut buf[..read_len];
+
+            if first_chunk {
+                self.spi
+                    .transaction(&mut [
+                        embedded_hal_async::spi::Operation::Write(&[FIFO_BURST]),
+                        embedded_hal_async::spi::Operation::Read(chunk),
+                    ])
+                    .await
+                    .map_err(|_| Error::Spi)?;
+                first_chunk = false;
+            } else {
+                self.spi
+                    .transaction(&mut [embedded_hal_async::spi::Operation::Read(chunk)])
+                    .await
+                    .map_err(|_| Error::Spi)?;
+            }
+            remaining -= read_len as u32;
+
+            let chunk: &[u8] = chunk;
+            let mut scan_from = 0;
+
+            if !found_soi {
+                let mut soi_at = None;
+                for i in 0..chunk.len() {
+                    let prev = if i == 0 { carry } else { Some(chunk[i - 1]) };
+                    if prev == Some(0xFF) && chunk[i] == 0xD8 {
+                        soi_at = Some(i);
+                        break;
+                    }
+                }
+
+                match soi_at {
+                    Some(0) => {
+                        f(&[0xFF])?;
+                        emitted += 1;
+                        found_soi = true;
+                        scan_from = 0;
+                    }
+                    Some(i) => {
+                        found_soi = true;
+                        scan_from = i - 1;
+                    }
+                    None => {
+                        carry = chunk.last().copied();
+                        continue;
+                    }
+                }
+            }
+
+            let data = &chunk[scan_from..];
+            let mut eoi_at = None;
+            for i in 0..data.len() {
+                let prev = if i == 0 { carry } else { Some(data[i - 1]) };
+                if prev == Some(0xFF) && data[i] == 0xD9 {
+                    eoi_at = Some(i);
+                    break;
+                }
+            }
+
+            match eoi_at {
+                Some(i) => {
+                    let slice = &data[..=i];
+                    f(slice)?;
+                    emitted += slice.len() as u32;
+                    found_eoi = true;
+                }
+                None => {
+                    f(data)?;
+                    emitted += data.len() as u32;
+                    carry = data.last().copied();
+                }
+            }
+        }
+
+        Ok(emitted)
+    }
+
+    async fn send_resolution(&mut self) -> Result<(), Error> {
+        match self.resolution {
+            Resolution::Res160x120 => self.sensor_writeregs8_8(&OV2640_160x120_JPEG).await?,
+            Resolution::Res1024x768 => self.sensor_writeregs8_8(&OV2640_1024x768_JPEG).await?,
+            Resolution::Res1280x1024 => self.sensor_writeregs8_8(&OV2640_1280x1024_JPEG).await?,
+            Resolution::Res1600x1200 => self.sensor_writeregs8_8(&OV2640_1600x1200_JPEG).await?,
+            Resolution::Res176x144 => self.sensor_writeregs8_8(&OV2640_176x144_JPEG).await?,
+            Resolution::Res320x240 => self.sensor_writeregs8_8(&OV2640_320x240_JPEG).await?,
+            Resolution::Res352x288 => self.sensor_writeregs8_8(&OV2640_352x288_JPEG).await?,
+            Resolution::Res640x480 => self.sensor_writeregs8_8(&OV2640_640x480_JPEG).await?,
+            Resolution::Res800x600 => self.sensor_writeregs8_8(&OV2640_800x600_JPEG).await?,
+        }
+
+        Ok(())
+    }
+
+    async fn flush_fifo(&mut self) -> Result<(), Error> {
+        self.arduchip_write_reg(ARDUCHIP_FIFO, FIFO_CLEAR_MASK).await
+    }
+
+    async fn start_fifo(&mut self) -> Result<(), Error> {
+        self.arduchip_write_reg(ARDUCHIP_FIFO, FIFO_START_MASK).await
+    }
+
+    async fn arduchip_write(&mut self, addr: u8, data: u8) -> Result<(), Error> {
+        self.spi
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(&[addr]),
+                embedded_hal_async::spi::Operation::Write(&[data]),
+            ])
+            .await
+            .map_err(|_| Error::Spi)?;
+        Ok(())
+    }
+
+    async fn arduchip_read(&mut self, addr: u8) -> Result<u8, Error> {
+        let buf = &mut [0; 1];
+        self.spi
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(&mut [addr; 1]),
+                embedded_hal_async::spi::Operation::Read(buf),
+            ])
+            .await
+            .map_err(|_| Error::Spi)?;
+        Ok(buf[0])
+    }
+
+    async fn arduchip_write_reg(&mut self, addr: u8, data: u8) -> Result<(), Error> {
+        self.arduchip_write(addr | WRITE_FLAG, data).await
+    }
+
+    async fn arduchip_read_reg(&mut self, addr: u8) -> Result<u8, Error> {
+        self.arduchip_read(addr & 0x7F).await
+    }
+
+    async fn sensor_writeregs8_8(&mut self, regs: &[[u8; 2]]) -> Result<(), Error> {
+        for reg in regs {
+            self.sensor_writereg8_8(reg[0], reg[1]).await?;
+        }
+        Ok(())
+    }
+
+    async fn sensor_writereg8_8(&mut self, reg: u8, data: u8) -> Result<(), Error> {
+        self.i2c
+            .write(OV2640_ADDR, &[reg, data])
+            .await
+            .map_err(|_| Error::I2c)
+    }
+
+    async fn sensor_readreg8_8(&mut self, reg: u8, out: &mut [u8]) -> Result<(), Error> {
+        self.i2c
+            .write_read(OV2640_ADDR, &[reg], out)
+            .await
+            .map_err(|_| Error::I2c)
+    }
+}
@@ -55,19 +55,25 @@
 #![no_std]
 #![no_main]
 
-use core::{fmt, slice::IterMut};
+use core::{fmt, marker::PhantomData};
 
 use embedded_hal::{delay::DelayNs, i2c::I2c, spi::SpiDevice};
 use ov2640_registers::*;
+use sensor::Sensor;
 
 mod ov2640_registers;
+mod ov5642_registers;
+pub mod sensor;
+pub mod mjpeg;
+
+#[cfg(feature = "async")]
+pub mod asynch;
+
+pub use sensor::{Ov2640, Ov5642};
 
 const ARDUCHIP_TEST1: u8 = 0x00;
 const ARDUCHIP_FIFO: u8 = 0x04;
 const ARDUCHIP_TRIG: u8 = 0x41;
-const OV2640_ADDR: u8 = 0x60 >> 1;
-const OV2640_CHIPID_HIGH: u8 = 0x0A;
-const OV2640_CHIPID_LOW: u8 = 0x0B;
 const FIFO_CLEAR_MASK: u8 = 0x01;
 const FIFO_START_MASK: u8 = 0x02;
 const FIFO_BURST: u8 = 0x3C;
@@ -76,6 +82,11 @@ const FIFO_SIZE2: u8 = 0x43;
 const FIFO_SIZE3: u8 = 0x44;
 const CAP_DONE_MASK: u8 = 0x08;
 const WRITE_FLAG: u8 = 0x80;
+// 14-byte file header + 40-byte BITMAPINFOHEADER + 12 bytes of RGB565
+// BI_BITFIELDS channel masks (required since biBitCount 16 + BI_RGB would
+// be read back as XRGB1555, not RGB565).
+const BMP_HEADER_LEN: u32 = 66;
+const CAPTURE_POLL_INTERVAL_MS: u32 = 1;
 
 #[derive(fmt::Debug)]
 /// Possible errors which can happen during communication
@@ -83,7 +94,9 @@ pub enum Error {
     Spi,
     I2c,
     Pin,
-    OutOfBounds
+    OutOfBounds,
+    /// [`Arducam::capture_blocking`] gave up waiting for the capture-done bit
+    Timeout,
 }
 
 #[derive(Debug)]
@@ -100,34 +113,126 @@ pub enum Resolution {
     Res1600x1200
 }
 
+impl Resolution {
+    /// Returns the pixel (width, height) of this resolution
+    pub(crate) fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Resolution::Res160x120 => (160, 120),
+            Resolution::Res176x144 => (176, 144),
+            Resolution::Res320x240 => (320, 240),
+            Resolution::Res352x288 => (352, 288),
+            Resolution::Res640x480 => (640, 480),
+            Resolution::Res800x600 => (800, 600),
+            Resolution::Res1024x768 => (1024, 768),
+            Resolution::Res1280x1024 => (1280, 1024),
+            Resolution::Res1600x1200 => (1600, 1200),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 /// Image formats which Arducam can handle
 pub enum ImageFormat {
-    // BMP,
-    // RAW,
+    /// Uncompressed RGB565, wrapped in a BMP file/info header by
+    /// [`Arducam::read_captured_bmp`]
+    Bmp,
+    /// Uncompressed RGB565, read as a raw pixel blob with
+    /// [`Arducam::read_captured_image`]
+    Raw,
     JPEG
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Brightness level, in discrete steps from the sensor's neutral point
+pub enum Brightness {
+    MinusTwo,
+    MinusOne,
+    Zero,
+    PlusOne,
+    PlusTwo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Contrast level, in discrete steps from the sensor's neutral point
+pub enum Contrast {
+    MinusTwo,
+    MinusOne,
+    Zero,
+    PlusOne,
+    PlusTwo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Saturation level, in discrete steps from the sensor's neutral point
+pub enum Saturation {
+    MinusTwo,
+    MinusOne,
+    Zero,
+    PlusOne,
+    PlusTwo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// OV2640 special DSP color effects
+pub enum SpecialEffect {
+    Normal,
+    Antique,
+    Bluish,
+    Greenish,
+    BlackAndWhite,
+    Negative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// White balance / light source presets
+pub enum LightMode {
+    Auto,
+    Sunny,
+    Cloudy,
+    Office,
+    Home,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Exposure compensation level, in discrete steps from the sensor's neutral point
+pub enum Exposure {
+    MinusTwo,
+    MinusOne,
+    Zero,
+    PlusOne,
+    PlusTwo,
+}
+
 /// Main struct responsible for communicating with Arducam
-pub struct Arducam<SPI, I2C> {
+///
+/// Generic over the sensor sitting behind the ArduChip (`S`, defaulting to
+/// the original [`Ov2640`]): the ArduChip SPI/FIFO protocol implemented
+/// here is shared by every ArduCAM module, while everything sensor-specific
+/// (I2C address, chip-id, JPEG init and resolution tables) is dispatched
+/// through the [`Sensor`] trait. The `Bmp`/`Raw` init sequence and the
+/// image-tuning setters always write OV2640 registers, whatever `S` is.
+pub struct Arducam<SPI, I2C, S = Ov2640> {
     spi: SPI,
     i2c: I2C,
     format: ImageFormat,
-    resolution: Resolution
+    resolution: Resolution,
+    sensor: PhantomData<S>,
 }
 
-impl<SPI, I2C> Arducam<SPI, I2C>
+impl<SPI, I2C, S> Arducam<SPI, I2C, S>
 where
     SPI: SpiDevice,
     I2C: I2c,
+    S: Sensor,
 {
     /// Creates a new Arducam instance but doesn't initialize it
-    pub fn new(spi: SPI, i2c: I2C, resolution: Resolution, format: ImageFormat) -> Arducam<SPI, I2C> {
+    pub fn new(spi: SPI, i2c: I2C, resolution: Resolution, format: ImageFormat) -> Arducam<SPI, I2C, S> {
         Arducam {
             spi,
             i2c,
             format,
             resolution,
+            sensor: PhantomData,
         }
     }
 
@@ -140,24 +245,14 @@ where
         delay.delay_ms(100);
         self.arduchip_write_reg(0x07, 0x00)?;
         delay.delay_ms(100);
-        self.sensor_writereg8_8(0xFF, 0x01)?;
-        delay.delay_ms(100);
-        self.sensor_writereg8_8(0x12, 0x80)?;
-        delay.delay_ms(100);
 
-        // if self.format == ImageFormat::JPEG {
-            unsafe {
-                self.sensor_writeregs8_8(&OV2640_JPEG_INIT)?;
-                self.sensor_writeregs8_8(&OV2640_YUV422)?;
-                self.sensor_writeregs8_8(&OV2640_JPEG)?;
+        match self.format {
+            ImageFormat::JPEG => S::write_regs(&mut self.i2c, S::init_sequence())?,
+            ImageFormat::Bmp | ImageFormat::Raw => {
+                self.sensor_writeregs8_8(&OV2640_RAW_INIT_SEQUENCE)?
             }
-            self.sensor_writereg8_8(0xFF, 0x01)?;
-            self.sensor_writereg8_8(0x15, 0x00)?;
-            self.send_resolution()?;
-        // }
-        // else {
-        //     unsafe { self.sensor_writeregs8_8(&OV2640_QVGA)?; }
-        // }
+        }
+        self.send_resolution()?;
 
         Ok(())
     }
@@ -169,22 +264,120 @@ where
         Ok(())
     }
 
+    /// Sets image brightness
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the DSP brightness registers and does not re-run JPEG init.
+    pub fn set_brightness(&mut self, brightness: Brightness) -> Result<(), Error> {
+        let regs: &[[u8; 2]] = match brightness {
+            Brightness::MinusTwo => &OV2640_BRIGHTNESS_MINUS_TWO,
+            Brightness::MinusOne => &OV2640_BRIGHTNESS_MINUS_ONE,
+            Brightness::Zero => &OV2640_BRIGHTNESS_ZERO,
+            Brightness::PlusOne => &OV2640_BRIGHTNESS_PLUS_ONE,
+            Brightness::PlusTwo => &OV2640_BRIGHTNESS_PLUS_TWO,
+        };
+        self.sensor_writeregs8_8(regs)
+    }
+
+    /// Sets image contrast
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the DSP contrast registers and does not re-run JPEG init.
+    pub fn set_contrast(&mut self, contrast: Contrast) -> Result<(), Error> {
+        let regs: &[[u8; 2]] = match contrast {
+            Contrast::MinusTwo => &OV2640_CONTRAST_MINUS_TWO,
+            Contrast::MinusOne => &OV2640_CONTRAST_MINUS_ONE,
+            Contrast::Zero => &OV2640_CONTRAST_ZERO,
+            Contrast::PlusOne => &OV2640_CONTRAST_PLUS_ONE,
+            Contrast::PlusTwo => &OV2640_CONTRAST_PLUS_TWO,
+        };
+        self.sensor_writeregs8_8(regs)
+    }
+
+    /// Sets image color saturation
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the DSP saturation registers and does not re-run JPEG init.
+    pub fn set_saturation(&mut self, saturation: Saturation) -> Result<(), Error> {
+        let regs: &[[u8; 2]] = match saturation {
+            Saturation::MinusTwo => &OV2640_SATURATION_MINUS_TWO,
+            Saturation::MinusOne => &OV2640_SATURATION_MINUS_ONE,
+            Saturation::Zero => &OV2640_SATURATION_ZERO,
+            Saturation::PlusOne => &OV2640_SATURATION_PLUS_ONE,
+            Saturation::PlusTwo => &OV2640_SATURATION_PLUS_TWO,
+        };
+        self.sensor_writeregs8_8(regs)
+    }
+
+    /// Sets the DSP special color effect
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the DSP effect registers and does not re-run JPEG init.
+    pub fn set_special_effect(&mut self, effect: SpecialEffect) -> Result<(), Error> {
+        let regs: &[[u8; 2]] = match effect {
+            SpecialEffect::Normal => &OV2640_EFFECT_NORMAL,
+            SpecialEffect::Antique => &OV2640_EFFECT_ANTIQUE,
+            SpecialEffect::Bluish => &OV2640_EFFECT_BLUISH,
+            SpecialEffect::Greenish => &OV2640_EFFECT_GREENISH,
+            SpecialEffect::BlackAndWhite => &OV2640_EFFECT_BLACK_AND_WHITE,
+            SpecialEffect::Negative => &OV2640_EFFECT_NEGATIVE,
+        };
+        self.sensor_writeregs8_8(regs)
+    }
+
+    /// Sets the white balance light-source preset
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the AWB gain registers and does not re-run JPEG init.
+    pub fn set_light_mode(&mut self, light_mode: LightMode) -> Result<(), Error> {
+        let regs: &[[u8; 2]] = match light_mode {
+            LightMode::Auto => &OV2640_LIGHT_MODE_AUTO,
+            LightMode::Sunny => &OV2640_LIGHT_MODE_SUNNY,
+            LightMode::Cloudy => &OV2640_LIGHT_MODE_CLOUDY,
+            LightMode::Office => &OV2640_LIGHT_MODE_OFFICE,
+            LightMode::Home => &OV2640_LIGHT_MODE_HOME,
+        };
+        self.sensor_writeregs8_8(regs)
+    }
+
+    /// Sets exposure compensation
+    ///
+    /// Unlike brightness/contrast/saturation/effect/light-mode, this isn't
+    /// a canned register table: it biases the sensor-bank manual-AEC target
+    /// directly, splitting the 10-bit value across `0x10` (AEC[9:2]) and
+    /// `0x04`'s two low bits (AEC[1:0], alongside that register's other
+    /// bits, left at their `OV2640_JPEG_INIT` baseline); `0x45`'s
+    /// extension bits are cleared since all five steps stay inside the
+    /// 10-bit range.
+    ///
+    /// Safe to call at any point after [`Arducam::init`]; it only touches
+    /// the AEC registers and does not re-run JPEG init.
+    pub fn set_exposure(&mut self, exposure: Exposure) -> Result<(), Error> {
+        let target: u16 = match exposure {
+            Exposure::MinusTwo => 0x080,
+            Exposure::MinusOne => 0x140,
+            Exposure::Zero => 0x200,
+            Exposure::PlusOne => 0x2c0,
+            Exposure::PlusTwo => 0x380,
+        };
+        let regs = [
+            [0xff, 0x01],
+            [0x45, 0x00],
+            [0x10, (target >> 2) as u8],
+            [0x04, 0x28 | (target & 0x03) as u8],
+        ];
+        self.sensor_writeregs8_8(&regs)
+    }
+
     /// Checks if Arducam is still connected to SPI bus
     pub fn is_connected(&mut self) -> Result<bool, Error> {
         let test_value = 0x52;
         self.arduchip_write_reg(ARDUCHIP_TEST1, test_value)?;
         let result = self.arduchip_read_reg(ARDUCHIP_TEST1)?;
 
-        let valid_ov2640_chipid1 = [0x26, 0x41];
-        let valid_ov2640_chipid2 = [0x26, 0x42];
         let chipid = self.get_sensor_chipid()?;
 
-        if test_value == result && chipid == valid_ov2640_chipid1 || chipid == valid_ov2640_chipid2 {
-            Ok(true)
-        }
-        else {
-            Ok(false)
-        }
+        Ok(test_value == result && S::valid_chipids().contains(&chipid))
     }
 
     /// Sends image capture request
@@ -199,6 +392,31 @@ where
         self.arduchip_read_reg(ARDUCHIP_TRIG).map(|result| { result & CAP_DONE_MASK != 0 })
     }
 
+    /// Starts a capture and blocks until it completes, instead of leaving
+    /// the caller to open-code an unbounded `while !is_capture_done() {}`
+    /// spin. Polls the capture-done bit every `CAPTURE_POLL_INTERVAL_MS`
+    /// milliseconds, up to a retry count derived from `timeout_ms`.
+    ///
+    /// # Returns
+    /// `Err(Error::Timeout)` if the ArduChip never sets the capture-done bit
+    /// within `timeout_ms`.
+    pub fn capture_blocking<D>(&mut self, delay: &mut D, timeout_ms: u32) -> Result<(), Error>
+    where
+        D: DelayNs,
+    {
+        self.start_capture()?;
+
+        let max_retries = timeout_ms / CAPTURE_POLL_INTERVAL_MS;
+        for _ in 0..=max_retries {
+            if self.is_capture_done()? {
+                return Ok(());
+            }
+            delay.delay_ms(CAPTURE_POLL_INTERVAL_MS);
+        }
+
+        Err(Error::Timeout)
+    }
+
     /// Saves captured image to provided mutable slice
     /// It is important to be sure if that slice will be big enough for image data
     /// otherwise data will be cut
@@ -217,6 +435,172 @@ where
         Ok(())
     }
 
+    /// Saves a captured `Bmp`/`Raw` frame as a directly viewable BMP file:
+    /// a 66-byte BMP file/info header (BITMAPINFOHEADER plus BI_BITFIELDS
+    /// channel masks), computed from the currently configured [`Resolution`]
+    /// for 16-bit-per-pixel RGB565, followed by the raw pixel data read
+    /// straight out of the FIFO.
+    ///
+    /// `out` must be at least `66 + width * height * 2` bytes; anything
+    /// past that is left untouched. Only meaningful when [`Arducam::init`]
+    /// was run with [`ImageFormat::Bmp`] or [`ImageFormat::Raw`], since a
+    /// JPEG-format capture isn't a fixed-size RGB565 pixel buffer.
+    ///
+    /// # Returns
+    /// The total number of bytes written, header included.
+    pub fn read_captured_bmp(&mut self, out: &mut [u8]) -> Result<u32, Error> {
+        let (width, height) = self.resolution.dimensions();
+        let pixel_len = width * height * 2;
+        let total_len = BMP_HEADER_LEN + pixel_len;
+
+        if (out.len() as u32) < total_len {
+            return Err(Error::OutOfBounds);
+        }
+
+        let header = &mut out[..BMP_HEADER_LEN as usize];
+        header[0..2].copy_from_slice(b"BM");
+        header[2..6].copy_from_slice(&total_len.to_le_bytes());
+        header[6..8].copy_from_slice(&[0; 2]);
+        header[8..10].copy_from_slice(&[0; 2]);
+        header[10..14].copy_from_slice(&BMP_HEADER_LEN.to_le_bytes());
+        header[14..18].copy_from_slice(&40u32.to_le_bytes());
+        header[18..22].copy_from_slice(&(width as i32).to_le_bytes());
+        // Negative height marks a top-down bitmap, matching the row order
+        // the FIFO streams pixels out in, so rows don't need reversing.
+        header[22..26].copy_from_slice(&(-(height as i32)).to_le_bytes());
+        header[26..28].copy_from_slice(&1u16.to_le_bytes());
+        header[28..30].copy_from_slice(&16u16.to_le_bytes());
+        // BI_BITFIELDS: the FIFO gives us RGB565, which a BI_RGB 16bpp
+        // bitmap would otherwise be misread as XRGB1555.
+        header[30..34].copy_from_slice(&3u32.to_le_bytes());
+        header[34..38].copy_from_slice(&pixel_len.to_le_bytes());
+        header[38..54].copy_from_slice(&[0; 16]);
+        header[54..58].copy_from_slice(&0xF800u32.to_le_bytes());
+        header[58..62].copy_from_slice(&0x07E0u32.to_le_bytes());
+        header[62..66].copy_from_slice(&0x001Fu32.to_le_bytes());
+
+        let pixels = &mut out[BMP_HEADER_LEN as usize..total_len as usize];
+        self.spi.transaction(&mut [
+            embedded_hal::spi::Operation::Write(&[FIFO_BURST]),
+            embedded_hal::spi::Operation::Read(pixels),
+        ]).map_err(|_| {Error::Spi})?;
+
+        self.flush_fifo()?;
+        Ok(total_len)
+    }
+
+    /// Streams a captured image out of the FIFO in fixed-size blocks, handing
+    /// each block to `f` as it arrives instead of requiring the caller to
+    /// buffer the whole frame.
+    ///
+    /// The burst command is only sent once; subsequent chunks keep clocking
+    /// out the FIFO contents from where the previous chunk left off, so the
+    /// ArduChip's internal read pointer does not need to be re-synced between
+    /// chunks.
+    ///
+    /// A small state machine tracks the JPEG start-of-image marker
+    /// (`0xFF 0xD8`) and end-of-image marker (`0xFF 0xD9`): bytes are only
+    /// forwarded to `f` once the SOI has been seen, and streaming stops as
+    /// soon as the EOI is observed. Because the marker pair can straddle a
+    /// chunk boundary, the last byte of each chunk is carried over and
+    /// compared against the first byte of the next one. This yields the
+    /// exact image length independent of [`Arducam::get_fifo_length`], which
+    /// is known to over-report on the OV2640 FIFO.
+    ///
+    /// # Returns
+    /// The exact number of image bytes emitted to `f` (from SOI through EOI,
+    /// inclusive).
+    pub fn read_captured_image_chunked<const N: usize>(
+        &mut self,
+        mut f: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<u32, Error> {
+        let mut remaining = self.get_fifo_length()?;
+        let mut buf = [0u8; N];
+        let mut first_chunk = true;
+
+        let mut found_soi = false;
+        let mut found_eoi = false;
+        let mut carry: Option<u8> = None;
+        let mut emitted = 0u32;
+
+        while remaining > 0 && !found_eoi {
+            let read_len = core::cmp::min(remaining as usize, N);
+            let chunk = &mut buf[..read_len];
+
+            if first_chunk {
+                self.spi.transaction(&mut [
+                    embedded_hal::spi::Operation::Write(&[FIFO_BURST]),
+                    embedded_hal::spi::Operation::Read(chunk),
+                ]).map_err(|_| {Error::Spi})?;
+                first_chunk = false;
+            } else {
+                self.spi.transaction(&mut [
+                    embedded_hal::spi::Operation::Read(chunk),
+                ]).map_err(|_| {Error::Spi})?;
+            }
+            remaining -= read_len as u32;
+
+            let chunk: &[u8] = chunk;
+            let mut scan_from = 0;
+
+            if !found_soi {
+                let mut soi_at = None;
+                for i in 0..chunk.len() {
+                    let prev = if i == 0 { carry } else { Some(chunk[i - 1]) };
+                    if prev == Some(0xFF) && chunk[i] == 0xD8 {
+                        soi_at = Some(i);
+                        break;
+                    }
+                }
+
+                match soi_at {
+                    Some(0) => {
+                        // The 0xFF half of the marker was the last byte of the
+                        // previous (discarded) chunk; emit it before the rest.
+                        f(&[0xFF])?;
+                        emitted += 1;
+                        found_soi = true;
+                        scan_from = 0;
+                    }
+                    Some(i) => {
+                        found_soi = true;
+                        scan_from = i - 1;
+                    }
+                    None => {
+                        carry = chunk.last().copied();
+                        continue;
+                    }
+                }
+            }
+
+            let data = &chunk[scan_from..];
+            let mut eoi_at = None;
+            for i in 0..data.len() {
+                let prev = if i == 0 { carry } else { Some(data[i - 1]) };
+                if prev == Some(0xFF) && data[i] == 0xD9 {
+                    eoi_at = Some(i);
+                    break;
+                }
+            }
+
+            match eoi_at {
+                Some(i) => {
+                    let slice = &data[..=i];
+                    f(slice)?;
+                    emitted += slice.len() as u32;
+                    found_eoi = true;
+                }
+                None => {
+                    f(data)?;
+                    emitted += data.len() as u32;
+                    carry = data.last().copied();
+                }
+            }
+        }
+
+        Ok(emitted)
+    }
+
     /// Returns image length reported by arduchip in FIFO
     pub fn get_fifo_length(&mut self) -> Result<u32, Error> {
         let mut len_builder = (0u32, 0u32, 0u32);
@@ -228,29 +612,11 @@ where
 
     /// Returns sensor vendor and product ID
     pub fn get_sensor_chipid(&mut self) -> Result<[u8; 2], Error> {
-        let mut chipid: [u8; 2] = [0; 2];
-        self.sensor_writereg8_8(0xFF, 0x01)?;
-        self.sensor_readreg8_8(OV2640_CHIPID_HIGH, &mut chipid[0..1])?;
-        self.sensor_readreg8_8(OV2640_CHIPID_LOW, &mut chipid[1..2])?;
-        Ok(chipid)
+        S::read_chipid(&mut self.i2c)
     }
 
     fn send_resolution(&mut self) -> Result<(), Error> {
-        unsafe {
-            match self.resolution {
-                Resolution::Res160x120 => { self.sensor_writeregs8_8(&OV2640_160x120_JPEG)? },
-                Resolution::Res1024x768 => { self.sensor_writeregs8_8(&OV2640_1024x768_JPEG)? },
-                Resolution::Res1280x1024 => { self.sensor_writeregs8_8(&OV2640_1280x1024_JPEG)? },
-                Resolution::Res1600x1200 => { self.sensor_writeregs8_8(&OV2640_1600x1200_JPEG)? },
-                Resolution::Res176x144 => { self.sensor_writeregs8_8(&OV2640_176x144_JPEG)? },
-                Resolution::Res320x240 => { self.sensor_writeregs8_8(&OV2640_320x240_JPEG)? },
-                Resolution::Res352x288 => { self.sensor_writeregs8_8(&OV2640_352x288_JPEG)? },
-                Resolution::Res640x480 => { self.sensor_writeregs8_8(&OV2640_640x480_JPEG)? },
-                Resolution::Res800x600 => { self.sensor_writeregs8_8(&OV2640_800x600_JPEG)? },
-            }
-        }
-
-        Ok(())
+        S::write_regs(&mut self.i2c, S::resolution_regs(&self.resolution))
     }
 
     fn flush_fifo(&mut self) -> Result<(), Error> {
@@ -290,23 +656,21 @@ where
         self.arduchip_read(addr & 0x7F)
     }
 
+    /// Writes a table of raw OV2640 `(register, value)` pairs over I2C.
+    ///
+    /// This always addresses the OV2640's 8-bit register space directly,
+    /// independent of the active `S`: the brightness/contrast/saturation/
+    /// effect/light-mode tables above are OV2640 DSP registers and aren't
+    /// meaningful on other sensors.
     fn sensor_writeregs8_8(&mut self, regs: &[[u8; 2]]) -> Result<(), Error> {
         for reg in regs {
-            self.sensor_writereg8_8(reg[0], reg[1])?;
+            self.i2c.write(Ov2640::i2c_addr(), &[reg[0] & 0xFF, reg[1] & 0xFF]).map_err(|_| {Error::I2c})?;
         }
         Ok(())
     }
-
-    fn sensor_writereg8_8(&mut self, reg: u8, data: u8) -> Result<(), Error> {
-        self.i2c.write(OV2640_ADDR, &[reg & 0xFF, data & 0xFF]).map_err(|_| {Error::I2c})
-    }
-
-    fn sensor_readreg8_8(&mut self, reg: u8, out: &mut [u8]) -> Result<(), Error> {
-        self.i2c.write_read(OV2640_ADDR, &[reg & 0xFF], out).map_err(|_| {Error::I2c})
-    }
 }
 
-impl<SPI, I2C> fmt::Debug for Arducam<SPI, I2C>
+impl<SPI, I2C, S> fmt::Debug for Arducam<SPI, I2C, S>
 where
     SPI: fmt::Debug,
     I2C: fmt::Debug,
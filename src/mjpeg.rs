@@ -0,0 +1,273 @@
+//! MJPEG AVI recording.
+//!
+//! [`AviRecorder`] wraps an [`Arducam`] and a user-supplied sink, capturing
+//! JPEG frames and framing them as an MJPEG AVI (RIFF) container so a
+//! sequence of captures can be concatenated into a directly playable video
+//! file.
+
+use embedded_hal::{delay::DelayNs, i2c::I2c, spi::SpiDevice};
+
+use crate::{Arducam, Resolution};
+
+const RIFF_SIZE_OFFSET: u32 = 4;
+const AVIH_TOTAL_FRAMES_OFFSET: u32 = 48;
+const STRH_LENGTH_OFFSET: u32 = 140;
+const MOVI_SIZE_OFFSET: u32 = 216;
+const MOVI_DATA_START: u32 = 224;
+const AVIF_HASINDEX: u32 = 0x10;
+const AVIIF_KEYFRAME: u32 = 0x10;
+
+fn fourcc(tag: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*tag)
+}
+
+/// A writer sink able to seek, required so [`AviRecorder::finish`] can
+/// back-patch the RIFF/`movi`/`avih`/`strh` size fields once the final
+/// frame count is known.
+pub trait SeekableWrite {
+    type Error;
+
+    /// Writes `buf` at the current position, advancing it by `buf.len()`.
+    fn write(&mut self, buf: &[u8]) -> Result<(), Self::Error>;
+
+    /// Moves the write position to an absolute byte offset from the start.
+    fn seek_from_start(&mut self, offset: u32) -> Result<(), Self::Error>;
+}
+
+/// Errors which can happen while recording an MJPEG AVI
+#[derive(Debug)]
+pub enum Error<WE> {
+    Camera(crate::Error),
+    Io(WE),
+    /// The recorder's fixed-capacity frame index is full
+    TooManyFrames,
+    /// `fps` was zero
+    InvalidFrameRate,
+}
+
+/// Records a sequence of JPEG frames from an [`Arducam`] into an MJPEG AVI
+/// container written through a [`SeekableWrite`] sink.
+///
+/// Frame offsets are tracked in a fixed-capacity array sized by
+/// `MAX_FRAMES`, since the crate is `no_std` and cannot grow a `Vec` to fit
+/// an arbitrary recording length.
+pub struct AviRecorder<SPI, I2C, W, const MAX_FRAMES: usize> {
+    camera: Arducam<SPI, I2C>,
+    writer: W,
+    width: u32,
+    height: u32,
+    micros_per_frame: u32,
+    frame_offsets: [u32; MAX_FRAMES],
+    frame_sizes: [u32; MAX_FRAMES],
+    frame_count: usize,
+    pos: u32,
+}
+
+impl<SPI, I2C, W, const MAX_FRAMES: usize> AviRecorder<SPI, I2C, W, MAX_FRAMES>
+where
+    SPI: SpiDevice,
+    I2C: I2c,
+    W: SeekableWrite,
+{
+    /// Wraps `camera` and `writer`, writing the AVI header (with
+    /// placeholder size fields) for a recording at `resolution` and `fps`
+    /// frames per second.
+    pub fn new(
+        camera: Arducam<SPI, I2C>,
+        writer: W,
+        resolution: &Resolution,
+        fps: u32,
+    ) -> Result<Self, Error<W::Error>> {
+        if fps == 0 {
+            return Err(Error::InvalidFrameRate);
+        }
+
+        let (width, height) = resolution.dimensions();
+        let micros_per_frame = 1_000_000 / fps;
+
+        let mut recorder = AviRecorder {
+            camera,
+            writer,
+            width,
+            height,
+            micros_per_frame,
+            frame_offsets: [0; MAX_FRAMES],
+            frame_sizes: [0; MAX_FRAMES],
+            frame_count: 0,
+            pos: 0,
+        };
+
+        recorder.write_header()?;
+        Ok(recorder)
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<(), Error<W::Error>> {
+        self.writer.write(buf).map_err(Error::Io)?;
+        self.pos += buf.len() as u32;
+        Ok(())
+    }
+
+    fn write_header(&mut self) -> Result<(), Error<W::Error>> {
+        self.write(b"RIFF")?;
+        self.write(&0u32.to_le_bytes())?; // riff size, patched in finish()
+        self.write(b"AVI ")?;
+
+        self.write(b"LIST")?;
+        self.write(&192u32.to_le_bytes())?; // hdrl size
+        self.write(b"hdrl")?;
+
+        self.write(b"avih")?;
+        self.write(&56u32.to_le_bytes())?;
+        self.write(&self.micros_per_frame.to_le_bytes())?;
+        self.write(&0u32.to_le_bytes())?; // dwMaxBytesPerSec
+        self.write(&0u32.to_le_bytes())?; // dwPaddingGranularity
+        self.write(&AVIF_HASINDEX.to_le_bytes())?; // dwFlags
+        self.write(&0u32.to_le_bytes())?; // dwTotalFrames, patched in finish()
+        self.write(&0u32.to_le_bytes())?; // dwInitialFrames
+        self.write(&1u32.to_le_bytes())?; // dwStreams
+        self.write(&0u32.to_le_bytes())?; // dwSuggestedBufferSize
+        self.write(&self.width.to_le_bytes())?;
+        self.write(&self.height.to_le_bytes())?;
+        self.write(&[0u8; 16])?; // dwReserved[4]
+
+        self.write(b"LIST")?;
+        self.write(&116u32.to_le_bytes())?; // strl size
+        self.write(b"strl")?;
+
+        self.write(b"strh")?;
+        self.write(&56u32.to_le_bytes())?;
+        self.write(b"vids")?; // fccType
+        self.write(b"MJPG")?; // fccHandler
+        self.write(&0u32.to_le_bytes())?; // dwFlags
+        self.write(&0u16.to_le_bytes())?; // wPriority
+        self.write(&0u16.to_le_bytes())?; // wLanguage
+        self.write(&0u32.to_le_bytes())?; // dwInitialFrames
+        self.write(&1u32.to_le_bytes())?; // dwScale
+        self.write(&fps_rate(self.micros_per_frame).to_le_bytes())?; // dwRate
+        self.write(&0u32.to_le_bytes())?; // dwStart
+        self.write(&0u32.to_le_bytes())?; // dwLength, patched in finish()
+        self.write(&0u32.to_le_bytes())?; // dwSuggestedBufferSize
+        self.write(&0xFFFFFFFFu32.to_le_bytes())?; // dwQuality
+        self.write(&0u32.to_le_bytes())?; // dwSampleSize
+        self.write(&[0u8; 8])?; // rcFrame
+
+        self.write(b"strf")?;
+        self.write(&40u32.to_le_bytes())?;
+        self.write(&40u32.to_le_bytes())?; // biSize
+        self.write(&self.width.to_le_bytes())?; // biWidth
+        self.write(&self.height.to_le_bytes())?; // biHeight
+        self.write(&1u16.to_le_bytes())?; // biPlanes
+        self.write(&24u16.to_le_bytes())?; // biBitCount
+        self.write(&fourcc(b"MJPG").to_le_bytes())?; // biCompression
+        self.write(&(self.width * self.height * 3).to_le_bytes())?; // biSizeImage
+        self.write(&0u32.to_le_bytes())?; // biXPelsPerMeter
+        self.write(&0u32.to_le_bytes())?; // biYPelsPerMeter
+        self.write(&0u32.to_le_bytes())?; // biClrUsed
+        self.write(&0u32.to_le_bytes())?; // biClrImportant
+
+        self.write(b"LIST")?;
+        self.write(&0u32.to_le_bytes())?; // movi size, patched in finish()
+        self.write(b"movi")?;
+
+        debug_assert_eq!(self.pos, MOVI_DATA_START);
+        Ok(())
+    }
+
+    /// Captures one frame from the camera into `scratch` and appends it to
+    /// the recording as a `00dc` chunk. Only the FIFO length reported by the
+    /// camera is read and written, cut to `scratch.len()` if longer.
+    pub fn capture_frame<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        scratch: &mut [u8],
+    ) -> Result<(), Error<W::Error>> {
+        if self.frame_count == MAX_FRAMES {
+            return Err(Error::TooManyFrames);
+        }
+
+        self.camera.start_capture().map_err(Error::Camera)?;
+        while !self.camera.is_capture_done().map_err(Error::Camera)? {
+            delay.delay_ms(1);
+        }
+        let len = core::cmp::min(
+            self.camera.get_fifo_length().map_err(Error::Camera)? as usize,
+            scratch.len(),
+        );
+        let frame = &mut scratch[..len];
+        self.camera
+            .read_captured_image(frame)
+            .map_err(Error::Camera)?;
+
+        self.write_frame(frame)
+    }
+
+    fn write_frame(&mut self, jpeg: &[u8]) -> Result<(), Error<W::Error>> {
+        if self.frame_count == MAX_FRAMES {
+            return Err(Error::TooManyFrames);
+        }
+
+        let offset = self.pos - MOVI_DATA_START;
+        self.write(b"00dc")?;
+        self.write(&(jpeg.len() as u32).to_le_bytes())?;
+        self.write(jpeg)?;
+        if jpeg.len() % 2 != 0 {
+            self.write(&[0u8])?;
+        }
+
+        self.frame_offsets[self.frame_count] = offset;
+        self.frame_sizes[self.frame_count] = jpeg.len() as u32;
+        self.frame_count += 1;
+        Ok(())
+    }
+
+    /// Writes the `idx1` index and back-patches the RIFF/`movi`/`avih`/
+    /// `strh` size fields now that the final frame count is known.
+    pub fn finish(mut self) -> Result<(Arducam<SPI, I2C>, W), Error<W::Error>> {
+        let movi_end = self.pos;
+
+        self.write(b"idx1")?;
+        self.write(&((self.frame_count as u32) * 16).to_le_bytes())?;
+        for i in 0..self.frame_count {
+            self.write(b"00dc")?;
+            self.write(&AVIIF_KEYFRAME.to_le_bytes())?;
+            self.write(&self.frame_offsets[i].to_le_bytes())?;
+            self.write(&self.frame_sizes[i].to_le_bytes())?;
+        }
+
+        let file_end = self.pos;
+
+        self.writer
+            .seek_from_start(RIFF_SIZE_OFFSET)
+            .map_err(Error::Io)?;
+        self.writer
+            .write(&(file_end - 8).to_le_bytes())
+            .map_err(Error::Io)?;
+
+        self.writer
+            .seek_from_start(AVIH_TOTAL_FRAMES_OFFSET)
+            .map_err(Error::Io)?;
+        self.writer
+            .write(&(self.frame_count as u32).to_le_bytes())
+            .map_err(Error::Io)?;
+
+        self.writer
+            .seek_from_start(STRH_LENGTH_OFFSET)
+            .map_err(Error::Io)?;
+        self.writer
+            .write(&(self.frame_count as u32).to_le_bytes())
+            .map_err(Error::Io)?;
+
+        self.writer
+            .seek_from_start(MOVI_SIZE_OFFSET)
+            .map_err(Error::Io)?;
+        self.writer
+            .write(&(movi_end - MOVI_SIZE_OFFSET - 4).to_le_bytes())
+            .map_err(Error::Io)?;
+
+        Ok((self.camera, self.writer))
+    }
+}
+
+fn fps_rate(micros_per_frame: u32) -> u32 {
+    1_000_000 / micros_per_frame
+}
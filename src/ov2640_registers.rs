@@ -0,0 +1,568 @@
+//! OV2640 sensor register tables.
+//!
+//! Each table is a flat sequence of `(register, value)` pairs written in
+//! order, either through `Sensor::write_regs` or `Arducam::sensor_writeregs8_8`
+//! depending on whether the table feeds sensor bring-up or a runtime tuning
+//! call. Bank-select writes
+//! (`0xFF` selects DSP bank `0x00` or sensor bank `0x01`) are included
+//! inline wherever a table depends on a particular bank, so tables can be
+//! pushed independently of whatever bank a previous operation left active.
+//! The exceptions are `OV2640_JPEG` and `OV2640_RGB565`, which carry no bank
+//! select and rely on the DSP bank left selected by `OV2640_YUV422`.
+
+pub(crate) const OV2640_JPEG_INIT: [[u8; 2]; 155] = [
+    [0xff, 0x00],
+    [0x2c, 0xff],
+    [0x2e, 0xdf],
+    [0xff, 0x01],
+    [0x3c, 0x32],
+    [0x11, 0x00],
+    [0x09, 0x02],
+    [0x04, 0x28],
+    [0x13, 0xe5],
+    [0x14, 0x48],
+    [0x2c, 0x0c],
+    [0x33, 0x78],
+    [0x3a, 0x33],
+    [0x3b, 0xfb],
+    [0x3e, 0x00],
+    [0x43, 0x11],
+    [0x16, 0x10],
+    [0x39, 0x02],
+    [0x35, 0x88],
+    [0x22, 0x0a],
+    [0x37, 0x40],
+    [0x23, 0x00],
+    [0x34, 0xa0],
+    [0x06, 0x02],
+    [0x06, 0x88],
+    [0x07, 0xc0],
+    [0x0d, 0xb7],
+    [0x0e, 0x01],
+    [0x4c, 0x00],
+    [0x4a, 0x81],
+    [0x21, 0x99],
+    [0x24, 0x40],
+    [0x25, 0x38],
+    [0x26, 0x82],
+    [0x5c, 0x00],
+    [0x63, 0x00],
+    [0x46, 0x22],
+    [0x0c, 0x3c],
+    [0x5d, 0x55],
+    [0x5e, 0x7d],
+    [0x5f, 0x7d],
+    [0x60, 0x55],
+    [0x61, 0x70],
+    [0x62, 0x80],
+    [0x7c, 0x05],
+    [0x20, 0x80],
+    [0x28, 0x30],
+    [0x6c, 0x00],
+    [0x6d, 0x80],
+    [0x6e, 0x00],
+    [0x70, 0x02],
+    [0x71, 0x94],
+    [0x73, 0xc1],
+    [0x3d, 0x34],
+    [0x5a, 0x57],
+    [0x12, 0x40],
+    [0x17, 0x11],
+    [0x18, 0x43],
+    [0x19, 0x00],
+    [0x1a, 0x4b],
+    [0x32, 0x09],
+    [0x37, 0xc0],
+    [0x4f, 0x60],
+    [0x50, 0xa8],
+    [0x6d, 0x00],
+    [0x3d, 0x38],
+    [0xff, 0x00],
+    [0xe5, 0x7f],
+    [0xf9, 0xc0],
+    [0x41, 0x24],
+    [0xe0, 0x14],
+    [0x76, 0xff],
+    [0x33, 0xa0],
+    [0x42, 0x20],
+    [0x43, 0x18],
+    [0x4c, 0x00],
+    [0x87, 0xd0],
+    [0x88, 0x3f],
+    [0xd7, 0x03],
+    [0xd9, 0x10],
+    [0xd3, 0x82],
+    [0xc8, 0x08],
+    [0xc9, 0x80],
+    [0x7c, 0x00],
+    [0x7d, 0x00],
+    [0x7c, 0x03],
+    [0x7d, 0x48],
+    [0x7d, 0x48],
+    [0x7c, 0x08],
+    [0x7d, 0x20],
+    [0x7d, 0x10],
+    [0x7d, 0x0e],
+    [0x90, 0x00],
+    [0x91, 0x0e],
+    [0x91, 0x1a],
+    [0x91, 0x31],
+    [0x91, 0x5a],
+    [0x91, 0x69],
+    [0x91, 0x75],
+    [0x91, 0x7e],
+    [0x91, 0x88],
+    [0x91, 0x8f],
+    [0x91, 0x96],
+    [0x91, 0xa3],
+    [0x91, 0xaf],
+    [0x91, 0xc4],
+    [0x91, 0xd7],
+    [0x91, 0xe8],
+    [0x91, 0x20],
+    [0x92, 0x00],
+    [0x93, 0x06],
+    [0x93, 0xe3],
+    [0x93, 0x05],
+    [0x93, 0x05],
+    [0x93, 0x00],
+    [0x93, 0x04],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x93, 0x00],
+    [0x96, 0x00],
+    [0x97, 0x08],
+    [0x97, 0x19],
+    [0x97, 0x02],
+    [0x97, 0x0c],
+    [0x97, 0x24],
+    [0x97, 0x30],
+    [0x97, 0x28],
+    [0x97, 0x26],
+    [0x97, 0x02],
+    [0x97, 0x98],
+    [0x97, 0x80],
+    [0x97, 0x00],
+    [0x97, 0x00],
+    [0xc3, 0xed],
+    [0xa4, 0x00],
+    [0xa8, 0x00],
+    [0xc5, 0x11],
+    [0xc6, 0x51],
+    [0xbf, 0x80],
+    [0xc7, 0x10],
+    [0xb6, 0x66],
+    [0xb8, 0xa5],
+    [0xb7, 0x64],
+    [0xb9, 0x7c],
+    [0xb3, 0xaf],
+    [0xb4, 0x97],
+    [0xb5, 0xff],
+    [0xb0, 0xc5],
+    [0xb1, 0x94],
+    [0xb2, 0x0f],
+    [0xc4, 0x5c],
+];
+
+pub(crate) const OV2640_YUV422: [[u8; 2]; 9] = [
+    [0xff, 0x00],
+    [0x05, 0x00],
+    [0xda, 0x10],
+    [0xd7, 0x03],
+    [0xdf, 0x00],
+    [0x33, 0x80],
+    [0x3c, 0x40],
+    [0xe1, 0x77],
+    [0x00, 0x00],
+];
+
+pub(crate) const OV2640_JPEG: [[u8; 2]; 6] = [
+    [0xe0, 0x14],
+    [0xe1, 0x77],
+    [0xe5, 0x1f],
+    [0xd7, 0x03],
+    [0xda, 0x10],
+    [0xe0, 0x00],
+];
+
+/// Selects uncompressed RGB565 DSP output instead of JPEG, for the
+/// `Bmp`/`Raw` [`ImageFormat`](crate::ImageFormat)s. Mirrors `OV2640_JPEG`'s
+/// structure, just with register `0xDA` (image format control) set to
+/// RGB565 instead of re-asserting YUV422/JPEG.
+pub(crate) const OV2640_RGB565: [[u8; 2]; 6] = [
+    [0xe0, 0x14],
+    [0xe1, 0x77],
+    [0xe5, 0x1f],
+    [0xd7, 0x03],
+    [0xda, 0x08],
+    [0xe0, 0x00],
+];
+
+const fn concat_init_sequence() -> [[u8; 2]; 174] {
+    let mut out = [[0u8; 2]; 174];
+    out[0] = [0xff, 0x01]; // select sensor bank 1
+    out[1] = [0x12, 0x80]; // soft reset
+
+    let mut i = 0;
+    while i < OV2640_JPEG_INIT.len() {
+        out[2 + i] = OV2640_JPEG_INIT[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < OV2640_YUV422.len() {
+        out[2 + OV2640_JPEG_INIT.len() + j] = OV2640_YUV422[j];
+        j += 1;
+    }
+    let mut k = 0;
+    while k < OV2640_JPEG.len() {
+        out[2 + OV2640_JPEG_INIT.len() + OV2640_YUV422.len() + k] = OV2640_JPEG[k];
+        k += 1;
+    }
+
+    let tail = 2 + OV2640_JPEG_INIT.len() + OV2640_YUV422.len() + OV2640_JPEG.len();
+    out[tail] = [0xff, 0x01]; // select sensor bank 1
+    out[tail + 1] = [0x15, 0x00];
+
+    out
+}
+
+/// Full sensor bring-up sequence for JPEG capture: the bank-select and
+/// soft-reset writes, the base `JPEG_INIT` table, the YUV422-to-JPEG
+/// format switch, and the JPEG compression enable, concatenated so
+/// [`Sensor::init_sequence`](crate::sensor::Sensor::init_sequence) can hand
+/// it over as a single table.
+pub(crate) static OV2640_INIT_SEQUENCE: [[u8; 2]; 174] = concat_init_sequence();
+
+const fn concat_raw_init_sequence() -> [[u8; 2]; 172] {
+    let mut out = [[0u8; 2]; 172];
+    out[0] = [0xff, 0x01]; // select sensor bank 1
+    out[1] = [0x12, 0x80]; // soft reset
+
+    let mut i = 0;
+    while i < OV2640_JPEG_INIT.len() {
+        out[2 + i] = OV2640_JPEG_INIT[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < OV2640_YUV422.len() {
+        out[2 + OV2640_JPEG_INIT.len() + j] = OV2640_YUV422[j];
+        j += 1;
+    }
+    let mut k = 0;
+    while k < OV2640_RGB565.len() {
+        out[2 + OV2640_JPEG_INIT.len() + OV2640_YUV422.len() + k] = OV2640_RGB565[k];
+        k += 1;
+    }
+
+    out
+}
+
+/// Full sensor bring-up sequence for uncompressed RGB565 capture (the
+/// `Bmp`/`Raw` [`ImageFormat`](crate::ImageFormat)s): identical to
+/// `OV2640_INIT_SEQUENCE` up through the YUV422 format switch, but finishes
+/// by selecting RGB565 DSP output instead of enabling JPEG compression.
+pub(crate) static OV2640_RAW_INIT_SEQUENCE: [[u8; 2]; 172] = concat_raw_init_sequence();
+
+// Each resolution table selects DSP bank 0x00 and writes the low bytes of
+// `width / 4` and `height / 4` into the 0x5a/0x5b output-window registers,
+// with 0x5c carrying the high bit of each when it doesn't fit in 8 bits.
+
+pub(crate) const OV2640_160x120_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x28],
+    [0x5b, 0x1e],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_176x144_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x2c],
+    [0x5b, 0x24],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_320x240_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x50],
+    [0x5b, 0x3c],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_352x288_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x58],
+    [0x5b, 0x48],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_640x480_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0xa0],
+    [0x5b, 0x78],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_800x600_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0xc8],
+    [0x5b, 0x96],
+    [0x5c, 0x00],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_1024x768_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x00],
+    [0x5b, 0xc0],
+    [0x5c, 0x01],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_1280x1024_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x40],
+    [0x5b, 0x00],
+    [0x5c, 0x03],
+    [0xe0, 0x00],
+];
+
+pub(crate) const OV2640_1600x1200_JPEG: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xe0, 0x14],
+    [0x5a, 0x90],
+    [0x5b, 0x2c],
+    [0x5c, 0x03],
+    [0xe0, 0x00],
+];
+
+// Brightness tables select DSP bank 0x00 and write the 0x7c/0x7d indirect
+// brightness/contrast pointer pair, mirroring the `OV2640_JPEG_INIT` usage
+// of the same registers.
+
+pub(crate) const OV2640_BRIGHTNESS_MINUS_TWO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x09],
+    [0x7d, 0x00],
+    [0x7d, 0x28],
+];
+pub(crate) const OV2640_BRIGHTNESS_MINUS_ONE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x09],
+    [0x7d, 0x10],
+    [0x7d, 0x28],
+];
+pub(crate) const OV2640_BRIGHTNESS_ZERO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x09],
+    [0x7d, 0x20],
+    [0x7d, 0x28],
+];
+pub(crate) const OV2640_BRIGHTNESS_PLUS_ONE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x09],
+    [0x7d, 0x30],
+    [0x7d, 0x28],
+];
+pub(crate) const OV2640_BRIGHTNESS_PLUS_TWO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x09],
+    [0x7d, 0x40],
+    [0x7d, 0x28],
+];
+
+pub(crate) const OV2640_CONTRAST_MINUS_TWO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x07],
+    [0x7d, 0x18],
+    [0x7d, 0x34],
+];
+pub(crate) const OV2640_CONTRAST_MINUS_ONE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x07],
+    [0x7d, 0x1c],
+    [0x7d, 0x2a],
+];
+pub(crate) const OV2640_CONTRAST_ZERO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x07],
+    [0x7d, 0x20],
+    [0x7d, 0x20],
+];
+pub(crate) const OV2640_CONTRAST_PLUS_ONE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x07],
+    [0x7d, 0x2a],
+    [0x7d, 0x1c],
+];
+pub(crate) const OV2640_CONTRAST_PLUS_TWO: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x04],
+    [0x7c, 0x07],
+    [0x7d, 0x34],
+    [0x7d, 0x18],
+];
+
+pub(crate) const OV2640_SATURATION_MINUS_TWO: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x02],
+    [0x7d, 0x40],
+    [0x7d, 0x40],
+];
+pub(crate) const OV2640_SATURATION_MINUS_ONE: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x02],
+    [0x7d, 0x50],
+    [0x7d, 0x50],
+];
+pub(crate) const OV2640_SATURATION_ZERO: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x02],
+    [0x7d, 0x68],
+    [0x7d, 0x68],
+];
+pub(crate) const OV2640_SATURATION_PLUS_ONE: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x02],
+    [0x7d, 0x80],
+    [0x7d, 0x80],
+];
+pub(crate) const OV2640_SATURATION_PLUS_TWO: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x02],
+    [0x7d, 0x90],
+    [0x7d, 0x90],
+];
+
+// Special-effect tables select DSP bank 0x00 and write the 0x7c/0x7d UV/Y
+// fixed-value pointers used by the sensor's color-effect block.
+
+pub(crate) const OV2640_EFFECT_NORMAL: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x00],
+    [0x7c, 0x05],
+    [0x7d, 0x80],
+];
+pub(crate) const OV2640_EFFECT_ANTIQUE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x18],
+    [0x7c, 0x05],
+    [0x7d, 0x40],
+    [0x7d, 0xa6],
+];
+pub(crate) const OV2640_EFFECT_BLUISH: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x18],
+    [0x7c, 0x05],
+    [0x7d, 0xa0],
+    [0x7d, 0x40],
+];
+pub(crate) const OV2640_EFFECT_GREENISH: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x18],
+    [0x7c, 0x05],
+    [0x7d, 0x40],
+    [0x7d, 0x40],
+];
+pub(crate) const OV2640_EFFECT_BLACK_AND_WHITE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x18],
+    [0x7c, 0x05],
+    [0x7d, 0x80],
+    [0x7d, 0x80],
+];
+pub(crate) const OV2640_EFFECT_NEGATIVE: [[u8; 2]; 5] = [
+    [0xff, 0x00],
+    [0x7c, 0x00],
+    [0x7d, 0x40],
+    [0x7c, 0x05],
+    [0x7d, 0x80],
+];
+
+// Light-mode tables select sensor bank 0x01 and write the AWB gain
+// registers (0x01/0x02) directly, disabling automatic white balance
+// (the `0xc7` bit, in DSP bank 0x00) for every preset except `Auto`.
+
+pub(crate) const OV2640_LIGHT_MODE_AUTO: [[u8; 2]; 4] = [
+    [0xff, 0x00],
+    [0xc7, 0x00],
+    [0xff, 0x01],
+    [0x13, 0xe7],
+];
+pub(crate) const OV2640_LIGHT_MODE_SUNNY: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xc7, 0x40],
+    [0xff, 0x01],
+    [0x13, 0xe5],
+    [0x01, 0x5a],
+    [0x02, 0x42],
+];
+pub(crate) const OV2640_LIGHT_MODE_CLOUDY: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xc7, 0x40],
+    [0xff, 0x01],
+    [0x13, 0xe5],
+    [0x01, 0x58],
+    [0x02, 0x60],
+];
+pub(crate) const OV2640_LIGHT_MODE_OFFICE: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xc7, 0x40],
+    [0xff, 0x01],
+    [0x13, 0xe5],
+    [0x01, 0x84],
+    [0x02, 0x4c],
+];
+pub(crate) const OV2640_LIGHT_MODE_HOME: [[u8; 2]; 6] = [
+    [0xff, 0x00],
+    [0xc7, 0x40],
+    [0xff, 0x01],
+    [0x13, 0xe5],
+    [0x01, 0x96],
+    [0x02, 0x40],
+];
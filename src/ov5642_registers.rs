@@ -0,0 +1,65 @@
+//! OV5642 sensor register tables.
+//!
+//! Unlike the OV2640, the OV5642 addresses its registers with 16 bits, so
+//! each table is a sequence of `(register, value)` tuples rather than the
+//! `[u8; 2]` pairs `ov2640_registers` uses. Tables are written in order
+//! through [`Sensor::write_regs`](crate::sensor::Sensor::write_regs).
+
+pub(crate) static OV5642_INIT_SEQUENCE: [(u16, u8); 20] = [
+    (0x3008, 0x80), // software reset
+    (0x3103, 0x93),
+    (0x3017, 0x7f),
+    (0x3018, 0xfc),
+    (0x3810, 0xc2),
+    (0x3615, 0xf8),
+    (0x3000, 0x00),
+    (0x3001, 0x00),
+    (0x3002, 0x00),
+    (0x3003, 0x00),
+    (0x3a00, 0x3c),
+    (0x3a1a, 0x05),
+    (0x3a13, 0x43),
+    (0x3a18, 0x00),
+    (0x3a19, 0x7c),
+    (0x3a08, 0x01),
+    (0x3a09, 0x27),
+    (0x3a0a, 0x00),
+    (0x3a0b, 0xf6),
+    (0x3008, 0x02), // wake up, resume streaming
+];
+
+pub(crate) static OV5642_320x240_JPEG: [(u16, u8); 6] = [
+    (0x3808, 0x01),
+    (0x3809, 0x40),
+    (0x380a, 0x00),
+    (0x380b, 0xf0),
+    (0x5001, 0xff),
+    (0x4300, 0x30), // JPEG output format
+];
+
+pub(crate) static OV5642_640x480_JPEG: [(u16, u8); 6] = [
+    (0x3808, 0x02),
+    (0x3809, 0x80),
+    (0x380a, 0x01),
+    (0x380b, 0xe0),
+    (0x5001, 0xff),
+    (0x4300, 0x30),
+];
+
+pub(crate) static OV5642_1024x768_JPEG: [(u16, u8); 6] = [
+    (0x3808, 0x04),
+    (0x3809, 0x00),
+    (0x380a, 0x03),
+    (0x380b, 0x00),
+    (0x5001, 0xff),
+    (0x4300, 0x30),
+];
+
+pub(crate) static OV5642_1600x1200_JPEG: [(u16, u8); 6] = [
+    (0x3808, 0x06),
+    (0x3809, 0x40),
+    (0x380a, 0x04),
+    (0x380b, 0xb0),
+    (0x5001, 0xff),
+    (0x4300, 0x30),
+];
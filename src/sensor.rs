@@ -0,0 +1,193 @@
+//! Abstraction over the image sensor sitting behind the ArduChip.
+//!
+//! The ArduChip SPI/FIFO protocol (capture trigger, FIFO burst read, length
+//! registers) is shared by every ArduCAM module, but the sensor behind it
+//! varies: I2C address, chip-ID registers, register addressing width, and
+//! the register tables themselves all differ between e.g. the OV2640 and
+//! the OV5642. [`Sensor`] captures exactly that boundary so [`Arducam`]'s
+//! core FIFO logic never has to know which sensor it's talking to.
+//!
+//! [`Arducam`]: crate::Arducam
+
+use embedded_hal::i2c::I2c;
+
+use crate::{Error, Resolution};
+
+/// An image sensor that can sit behind the ArduChip SPI/FIFO bridge.
+///
+/// Implementors own the full I2C register protocol for their sensor,
+/// including how wide a register address is and how a `(register, value)`
+/// pair is serialized onto the wire, so sensors with incompatible
+/// addressing (e.g. the OV2640's 8-bit registers vs. the OV5642's 16-bit
+/// registers) can coexist behind the same [`Arducam`](crate::Arducam).
+pub trait Sensor {
+    /// A single `(register, value)` pair from this sensor's register
+    /// tables, in whatever shape this sensor's addressing needs.
+    type RegPair: Copy + 'static;
+
+    /// 7-bit I2C address of the sensor
+    fn i2c_addr() -> u8;
+
+    /// Chip-id byte pairs this sensor is allowed to report; used by
+    /// [`Arducam::is_connected`](crate::Arducam::is_connected)
+    fn valid_chipids() -> &'static [[u8; 2]];
+
+    /// Register writes that bring the sensor from power-on reset to ready
+    /// for JPEG capture
+    fn init_sequence() -> &'static [Self::RegPair];
+
+    /// Register writes that select a given output resolution
+    fn resolution_regs(resolution: &Resolution) -> &'static [Self::RegPair];
+
+    /// Writes one `(register, value)` pair over I2C
+    fn write_reg<I2C: I2c>(i2c: &mut I2C, pair: Self::RegPair) -> Result<(), Error>;
+
+    /// Writes a whole table of `(register, value)` pairs over I2C, in order
+    fn write_regs<I2C: I2c>(i2c: &mut I2C, regs: &[Self::RegPair]) -> Result<(), Error> {
+        for &pair in regs {
+            Self::write_reg(i2c, pair)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the sensor's two-byte chip-id
+    fn read_chipid<I2C: I2c>(i2c: &mut I2C) -> Result<[u8; 2], Error>;
+}
+
+mod ov2640 {
+    use super::Sensor;
+    use crate::ov2640_registers::*;
+    use crate::{Error, Resolution};
+    use embedded_hal::i2c::I2c;
+
+    const OV2640_ADDR: u8 = 0x60 >> 1;
+    const OV2640_CHIPID_HIGH: u8 = 0x0A;
+    const OV2640_CHIPID_LOW: u8 = 0x0B;
+
+    /// The OV2640, the 2MP sensor the original ArduCAM Mini shipped with.
+    /// Uses 8-bit register addressing.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Ov2640;
+
+    impl Sensor for Ov2640 {
+        type RegPair = [u8; 2];
+
+        fn i2c_addr() -> u8 {
+            OV2640_ADDR
+        }
+
+        fn valid_chipids() -> &'static [[u8; 2]] {
+            &[[0x26, 0x41], [0x26, 0x42]]
+        }
+
+        fn init_sequence() -> &'static [Self::RegPair] {
+            &OV2640_INIT_SEQUENCE
+        }
+
+        fn resolution_regs(resolution: &Resolution) -> &'static [Self::RegPair] {
+            match resolution {
+                Resolution::Res160x120 => &OV2640_160x120_JPEG,
+                Resolution::Res176x144 => &OV2640_176x144_JPEG,
+                Resolution::Res320x240 => &OV2640_320x240_JPEG,
+                Resolution::Res352x288 => &OV2640_352x288_JPEG,
+                Resolution::Res640x480 => &OV2640_640x480_JPEG,
+                Resolution::Res800x600 => &OV2640_800x600_JPEG,
+                Resolution::Res1024x768 => &OV2640_1024x768_JPEG,
+                Resolution::Res1280x1024 => &OV2640_1280x1024_JPEG,
+                Resolution::Res1600x1200 => &OV2640_1600x1200_JPEG,
+            }
+        }
+
+        fn write_reg<I2C: I2c>(i2c: &mut I2C, pair: Self::RegPair) -> Result<(), Error> {
+            i2c.write(OV2640_ADDR, &[pair[0], pair[1]])
+                .map_err(|_| Error::I2c)
+        }
+
+        fn read_chipid<I2C: I2c>(i2c: &mut I2C) -> Result<[u8; 2], Error> {
+            let mut chipid = [0u8; 2];
+            Self::write_reg(i2c, [0xFF, 0x01])?;
+            i2c.write_read(OV2640_ADDR, &[OV2640_CHIPID_HIGH], &mut chipid[0..1])
+                .map_err(|_| Error::I2c)?;
+            i2c.write_read(OV2640_ADDR, &[OV2640_CHIPID_LOW], &mut chipid[1..2])
+                .map_err(|_| Error::I2c)?;
+            Ok(chipid)
+        }
+    }
+}
+
+mod ov5642 {
+    use super::Sensor;
+    use crate::ov5642_registers::*;
+    use crate::{Error, Resolution};
+    use embedded_hal::i2c::I2c;
+
+    const OV5642_ADDR: u8 = 0x78 >> 1;
+    const OV5642_CHIPID_HIGH: u16 = 0x300A;
+    const OV5642_CHIPID_LOW: u16 = 0x300B;
+
+    /// The OV5642, a 5MP sensor used by later ArduCAM modules sharing the
+    /// same ArduChip SPI/FIFO bridge. Unlike the OV2640, its registers are
+    /// 16-bit addressed.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Ov5642;
+
+    impl Sensor for Ov5642 {
+        type RegPair = (u16, u8);
+
+        fn i2c_addr() -> u8 {
+            OV5642_ADDR
+        }
+
+        fn valid_chipids() -> &'static [[u8; 2]] {
+            &[[0x56, 0x42]]
+        }
+
+        fn init_sequence() -> &'static [Self::RegPair] {
+            &OV5642_INIT_SEQUENCE
+        }
+
+        fn resolution_regs(resolution: &Resolution) -> &'static [Self::RegPair] {
+            match resolution {
+                Resolution::Res320x240 => &OV5642_320x240_JPEG,
+                Resolution::Res640x480 => &OV5642_640x480_JPEG,
+                Resolution::Res1024x768 => &OV5642_1024x768_JPEG,
+                Resolution::Res1600x1200 => &OV5642_1600x1200_JPEG,
+                // The OV5642 doesn't have a dedicated table for every size
+                // the OV2640 supports; the others fall back to the next
+                // larger table, so the capture is at that table's resolution
+                // and no error is reported.
+                Resolution::Res160x120 | Resolution::Res176x144 => &OV5642_320x240_JPEG,
+                Resolution::Res352x288 => &OV5642_640x480_JPEG,
+                Resolution::Res800x600 => &OV5642_1024x768_JPEG,
+                Resolution::Res1280x1024 => &OV5642_1600x1200_JPEG,
+            }
+        }
+
+        fn write_reg<I2C: I2c>(i2c: &mut I2C, pair: Self::RegPair) -> Result<(), Error> {
+            let (reg, value) = pair;
+            let [reg_hi, reg_lo] = reg.to_be_bytes();
+            i2c.write(OV5642_ADDR, &[reg_hi, reg_lo, value])
+                .map_err(|_| Error::I2c)
+        }
+
+        fn read_chipid<I2C: I2c>(i2c: &mut I2C) -> Result<[u8; 2], Error> {
+            let mut chipid = [0u8; 2];
+            i2c.write_read(
+                OV5642_ADDR,
+                &OV5642_CHIPID_HIGH.to_be_bytes(),
+                &mut chipid[0..1],
+            )
+            .map_err(|_| Error::I2c)?;
+            i2c.write_read(
+                OV5642_ADDR,
+                &OV5642_CHIPID_LOW.to_be_bytes(),
+                &mut chipid[1..2],
+            )
+            .map_err(|_| Error::I2c)?;
+            Ok(chipid)
+        }
+    }
+}
+
+pub use ov2640::Ov2640;
+pub use ov5642::Ov5642;